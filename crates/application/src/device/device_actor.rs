@@ -59,8 +59,13 @@ impl DeviceActor {
 
         info!("Starting DeviceActor for {}", device.id);
 
-        // 1. Start Driver
-        if let Err(e) = driver.connect().await {
+        // 1. Start Driver (DeviceManager may already have connected it)
+        let initial = if driver.is_connected() {
+            Ok(())
+        } else {
+            driver.connect().await
+        };
+        if let Err(e) = initial {
             error!(device_id = %device.id, "Failed initial connection: {}", e);
         }
 
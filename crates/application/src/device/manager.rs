@@ -12,6 +12,13 @@ use infrastructure::pipeline::ConcretePipelineFactory; // NEW
 
 use crate::device::DeviceActor;
 
+/// A device that could not be brought up by `DeviceManager::start_devices`.
+#[derive(Debug, Clone)]
+pub struct DeviceStartFailure {
+    pub device_id: String,
+    pub error: String,
+}
+
 /// Manages the lifecycle of DeviceActors
 pub struct DeviceManager {
     // Map device_id -> (JoinHandle, CancelToken?)
@@ -32,8 +39,16 @@ impl DeviceManager {
         }
     }
 
-    pub async fn start_devices(&self, devices: Vec<Device>, tags: Vec<Tag>) {
+    /// Starts an actor per enabled device and returns the devices whose driver could not be
+    /// created or whose first connection attempt failed. Actors with a failed connection are
+    /// still spawned and keep retrying, so callers decide whether the failure is fatal.
+    pub async fn start_devices(
+        &self,
+        devices: Vec<Device>,
+        tags: Vec<Tag>,
+    ) -> Vec<DeviceStartFailure> {
         let mut actors = self.actors.lock().await;
+        let mut failures = Vec::new();
 
         // Group tags by device_id
         // Tags have optional device_id. If None, they are "legacy" or "virtual"?
@@ -78,7 +93,15 @@ impl DeviceManager {
                 DriverFactory::create_device_driver(device.clone(), tags_for_device.clone());
 
             match driver_res {
-                Ok(driver) => {
+                Ok(mut driver) => {
+                    if let Err(e) = driver.connect().await {
+                        warn!(device_id = %device.id, "Initial connection failed: {}", e);
+                        failures.push(DeviceStartFailure {
+                            device_id: device.id.clone(),
+                            error: e.to_string(),
+                        });
+                    }
+
                     let actor = DeviceActor::new(
                         device.clone(),
                         driver,
@@ -97,9 +120,15 @@ impl DeviceManager {
                 }
                 Err(e) => {
                     error!(device_id = %device.id, "Failed to create driver: {}", e);
+                    failures.push(DeviceStartFailure {
+                        device_id: device.id.clone(),
+                        error: e.to_string(),
+                    });
                 }
             }
         }
+
+        failures
     }
 
     pub async fn stop_all(&self) {
@@ -107,6 +136,8 @@ impl DeviceManager {
         for (id, handle) in actors.drain() {
             info!(device_id = %id, "Stopping device actor");
             handle.abort(); // Simple abort for now
+            // Wait for the task to drop its driver so ports are free for the next start
+            let _ = handle.await;
         }
         // Clear active tags
         self.active_tags.lock().await.clear();
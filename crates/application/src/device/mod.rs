@@ -2,4 +2,4 @@ pub mod device_actor;
 pub mod manager;
 
 pub use device_actor::DeviceActor;
-pub use manager::DeviceManager;
+pub use manager::{DeviceManager, DeviceStartFailure};
@@ -0,0 +1,54 @@
+use application::device::DeviceManager;
+use async_trait::async_trait;
+use domain::DomainEvent;
+use domain::device::Device;
+use domain::driver::DriverType;
+use domain::event::EventPublisher;
+use serde_json::json;
+use std::sync::Arc;
+
+struct NullPublisher;
+
+#[async_trait]
+impl EventPublisher for NullPublisher {
+    async fn publish(
+        &self,
+        _event: DomainEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_start_devices_reports_failed_devices() {
+    let manager = DeviceManager::new(Arc::new(NullPublisher));
+
+    let devices = vec![
+        Device::new("sim".to_string(), DriverType::Simulator, json!({}), true),
+        // Port does not exist: initial connection fails
+        Device::new(
+            "scale".to_string(),
+            DriverType::RS232,
+            json!({ "port": "/dev/does-not-exist-ifascada" }),
+            true,
+        ),
+        // Invalid connection config: driver cannot be created
+        Device::new("plc".to_string(), DriverType::Modbus, json!({}), true),
+        // Disabled devices are never started, so they cannot fail
+        Device::new(
+            "spare".to_string(),
+            DriverType::RS232,
+            json!({ "port": "/dev/does-not-exist-ifascada" }),
+            false,
+        ),
+    ];
+
+    let failures = manager.start_devices(devices, vec![]).await;
+    let mut failed: Vec<_> = failures.iter().map(|f| f.device_id.as_str()).collect();
+    failed.sort();
+
+    assert_eq!(failed, vec!["plc", "scale"]);
+    assert!(failures.iter().all(|f| !f.error.is_empty()));
+
+    manager.stop_all().await;
+}
@@ -7,13 +7,14 @@ use axum::{
     },
     routing::{get, post},
 };
-use futures::Stream;
+use futures::{Stream, stream::BoxStream};
 use serde_json::json;
 use std::{sync::Arc, time::Duration};
 use tokio_stream::StreamExt;
-use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::{BroadcastStream, IntervalStream};
 
-use crate::state::AppState;
+use crate::services::{ConfigService, DeviceTemplate};
+use crate::state::{AppState, SystemEvent, TagDelta};
 
 use tower_http::cors::{Any, CorsLayer};
 
@@ -34,6 +35,12 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/api/reports/{id}", get(get_report_details))
         .route("/api/reports/{id}/reprint", post(reprint_report))
         .route("/api/tags/{id}/history", get(get_tag_history))
+        .route("/api/device-templates", get(get_device_templates))
+        .route("/api/device-templates/{id}", get(get_device_template))
+        .route(
+            "/api/device-templates/{id}/apply",
+            post(apply_device_template),
+        )
         .layer(cors)
         .fallback_service(
             tower_http::services::ServeDir::new("static")
@@ -111,21 +118,55 @@ async fn send_command(
     }
 }
 
+const DEFAULT_SNAPSHOT_SECS: u64 = 30;
+
+#[derive(serde::Deserialize)]
+struct EventsQuery {
+    /// `full` (default) streams complete TagData, `delta` streams TagDelta
+    /// plus periodic TagSnapshot events.
+    mode: Option<String>,
+    snapshot_secs: Option<u64>,
+}
+
 async fn sse_handler(
     State(state): State<Arc<AppState>>,
+    axum::extract::Query(query): axum::extract::Query<EventsQuery>,
 ) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
     let rx = state.tx.subscribe();
-    let stream = BroadcastStream::new(rx).map(|msg| match msg {
-        Ok(event) => Event::default()
-            .json_data(event)
-            .map_err(|_| axum::Error::new("Serialization error")),
-        Err(_) => Ok(Event::default().comment("keep-alive")),
-    });
+    let is_delta = query.mode.as_deref() == Some("delta");
+
+    let stream: BoxStream<'static, Result<Event, axum::Error>> = if is_delta {
+        let updates = BroadcastStream::new(rx).map(|msg| match msg {
+            Ok(SystemEvent::TagChanged(tag)) => Some(SystemEvent::TagDelta(TagDelta::from(&tag))),
+            Ok(event) => Some(event),
+            // Lagged: missed deltas are recovered by the next snapshot
+            Err(_) => None,
+        });
+
+        // First tick fires immediately, so every client starts from a full snapshot
+        let period =
+            Duration::from_secs(query.snapshot_secs.unwrap_or(DEFAULT_SNAPSHOT_SECS).max(1));
+        let snapshots = IntervalStream::new(tokio::time::interval(period))
+            .map(move |_| Some(SystemEvent::TagSnapshot(state.tag_snapshot())));
+
+        Box::pin(snapshots.merge(updates).map(to_sse_event))
+    } else {
+        Box::pin(BroadcastStream::new(rx).map(|msg| to_sse_event(msg.ok())))
+    };
 
     Sse::new(stream)
         .keep_alive(axum::response::sse::KeepAlive::new().interval(Duration::from_secs(15)))
 }
 
+fn to_sse_event(event: Option<SystemEvent>) -> Result<Event, axum::Error> {
+    match event {
+        Some(event) => Event::default()
+            .json_data(event)
+            .map_err(|_| axum::Error::new("Serialization error")),
+        None => Ok(Event::default().comment("keep-alive")),
+    }
+}
+
 #[derive(serde::Deserialize)]
 struct Pagination {
     limit: Option<i64>,
@@ -554,3 +595,110 @@ async fn batch_print_events(
         Err(e) => Json(json!({ "error": e.to_string() })),
     }
 }
+
+async fn get_device_templates(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(json!(state.device_templates.list()))
+}
+
+async fn get_device_template(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    match state.device_templates.get(&id) {
+        Some(template) => Json(json!(template)),
+        None => Json(json!({ "error": "Device template not found" })),
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct ApplyTemplateRequest {
+    agent_id: String,
+    device_id: String,
+    name: Option<String>,
+    /// Merged over the template defaults (e.g. `{"port": "COM3", "slave_id": 5}`)
+    connection_config: Option<serde_json::Value>,
+}
+
+async fn apply_device_template(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<ApplyTemplateRequest>,
+) -> impl IntoResponse {
+    let Some(template) = state.device_templates.get(&id) else {
+        return Json(json!({ "error": "Device template not found" }));
+    };
+
+    match insert_device_from_template(&state.pool, template, &req).await {
+        Ok(tag_ids) => {
+            // Push the new device to the agent right away instead of waiting for its next reconnect
+            ConfigService::new(state.pool.clone(), state.mqtt_client.clone())
+                .sync_config(&req.agent_id)
+                .await;
+
+            Json(json!({
+                "status": "Device created from template",
+                "device_id": req.device_id,
+                "tag_ids": tag_ids
+            }))
+        }
+        Err(e) => Json(json!({ "error": e.to_string() })),
+    }
+}
+
+async fn insert_device_from_template(
+    pool: &sqlx::PgPool,
+    template: &DeviceTemplate,
+    req: &ApplyTemplateRequest,
+) -> Result<Vec<String>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO devices (id, edge_agent_id, name, driver_type, connection_config, enabled)
+        VALUES ($1, $2, $3, $4, $5, true)
+        "#,
+    )
+    .bind(&req.device_id)
+    .bind(&req.agent_id)
+    .bind(req.name.as_deref().unwrap_or(&template.name))
+    .bind(template.driver.as_str())
+    .bind(template.connection_config_with(req.connection_config.as_ref()))
+    .execute(&mut *tx)
+    .await?;
+
+    let mut tag_ids = Vec::with_capacity(template.tags.len());
+    for tag in &template.tags {
+        let tag_id = DeviceTemplate::tag_id(&req.device_id, &tag.key);
+        let value_type = if tag.value_type.is_composite() {
+            "Composite"
+        } else {
+            "Simple"
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO tags (
+                id, device_id, source_config, update_mode, update_config,
+                value_type, value_schema, pipeline_config, enabled, description
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, true, $9)
+            "#,
+        )
+        .bind(&tag_id)
+        .bind(&req.device_id)
+        .bind(&tag.source_config)
+        .bind(&tag.update_mode)
+        .bind(&tag.update_config)
+        .bind(value_type)
+        .bind(&tag.value_schema)
+        .bind(tag.pipeline.as_ref().map(|p| json!(p)))
+        .bind(&tag.description)
+        .execute(&mut *tx)
+        .await?;
+
+        tag_ids.push(tag_id);
+    }
+
+    tx.commit().await?;
+    Ok(tag_ids)
+}
@@ -27,12 +27,17 @@ impl ConfigService {
         if let Err(e) = self.mqtt_client.subscribe("scada/status/#").await {
             error!("Failed to subscribe to status updates: {}", e);
         }
+        if let Err(e) = self.mqtt_client.subscribe("scada/config/+/ack").await {
+            error!("Failed to subscribe to config acks: {}", e);
+        }
 
         let mut rx = self.mqtt_client.subscribe_messages();
 
         while let Ok(msg) = rx.recv().await {
             if msg.topic.starts_with("scada/status/") {
                 self.handle_status_message(msg).await;
+            } else if msg.topic.starts_with("scada/config/") && msg.topic.ends_with("/ack") {
+                self.handle_config_ack(msg).await;
             }
         }
     }
@@ -96,7 +101,41 @@ impl ConfigService {
         }
     }
 
-    async fn sync_config(&self, agent_id: &str) {
+    async fn handle_config_ack(&self, msg: MqttMessage) {
+        let agent_id = msg
+            .topic
+            .trim_start_matches("scada/config/")
+            .trim_end_matches("/ack");
+
+        match serde_json::from_slice::<serde_json::Value>(&msg.payload) {
+            Ok(ack) => {
+                let status = ack
+                    .get("status")
+                    .and_then(|s| s.as_str())
+                    .unwrap_or("UNKNOWN");
+                let version = ack.get("version").and_then(|v| v.as_str()).unwrap_or("-");
+                if status == "APPLIED" {
+                    info!("✅ Agent {} applied config {}", agent_id, version);
+                } else {
+                    warn!(
+                        agent_id = %agent_id,
+                        version = %version,
+                        failed_devices = %ack.get("failed_devices").cloned().unwrap_or_default(),
+                        "Agent did not apply config ({}): {}",
+                        status,
+                        ack.get("error").and_then(|e| e.as_str()).unwrap_or("no details")
+                    );
+                }
+            }
+            Err(e) => warn!("Failed to parse config ack from {}: {}", agent_id, e),
+        }
+
+        if let Err(e) = self.mqtt_client.ack(&msg.topic, msg.pkid).await {
+            error!("Failed to ack config ack message: {}", e);
+        }
+    }
+
+    pub async fn sync_config(&self, agent_id: &str) {
         match self.repo.get_agent_config(agent_id).await {
             Ok(config) => {
                 let config_topic = format!("scada/config/{}", agent_id);
@@ -0,0 +1,99 @@
+use domain::driver::DriverType;
+use domain::tag::{PipelineConfig, TagValueType};
+use serde::{Deserialize, Serialize};
+
+// Shipped inside the binary so the catalog does not depend on the working directory
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "mettler_toledo_scale.json",
+        include_str!("../../device_templates/mettler_toledo_scale.json"),
+    ),
+    (
+        "thermohygrometer_modbus.json",
+        include_str!("../../device_templates/thermohygrometer_modbus.json"),
+    ),
+    (
+        "energy_meter_modbus.json",
+        include_str!("../../device_templates/energy_meter_modbus.json"),
+    ),
+    (
+        "plc_io_modbus.json",
+        include_str!("../../device_templates/plc_io_modbus.json"),
+    ),
+];
+
+/// A known device model: default connection settings plus its register/parser map.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeviceTemplate {
+    pub id: String,
+    pub name: String,
+    pub manufacturer: String,
+    pub category: String,
+    pub description: String,
+    pub driver: DriverType,
+    pub connection_config: serde_json::Value,
+    pub tags: Vec<TemplateTag>,
+}
+
+/// Tag definition mirroring the `tags` table columns.
+/// `key` is combined with the device id to build the final tag id.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TemplateTag {
+    pub key: String,
+    pub description: String,
+    pub source_config: serde_json::Value,
+    pub update_mode: String,
+    pub update_config: serde_json::Value,
+    pub value_type: TagValueType,
+    #[serde(default)]
+    pub value_schema: Option<serde_json::Value>,
+    #[serde(default)]
+    pub pipeline: Option<PipelineConfig>,
+}
+
+impl DeviceTemplate {
+    pub fn tag_id(device_id: &str, key: &str) -> String {
+        format!("{}_{}", device_id, key)
+    }
+
+    /// Template defaults with the given fields (e.g. `port`, `slave_id`) replaced.
+    pub fn connection_config_with(
+        &self,
+        overrides: Option<&serde_json::Value>,
+    ) -> serde_json::Value {
+        let mut config = self.connection_config.clone();
+        if let (Some(base), Some(serde_json::Value::Object(extra))) =
+            (config.as_object_mut(), overrides)
+        {
+            for (key, value) in extra {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+        config
+    }
+}
+
+pub struct DeviceTemplateCatalog {
+    templates: Vec<DeviceTemplate>,
+}
+
+impl DeviceTemplateCatalog {
+    pub fn builtin() -> Self {
+        let templates = BUILTIN_TEMPLATES
+            .iter()
+            .map(|(file, json)| {
+                serde_json::from_str(json)
+                    .unwrap_or_else(|e| panic!("Invalid built-in device template {}: {}", file, e))
+            })
+            .collect();
+        Self { templates }
+    }
+
+    pub fn list(&self) -> &[DeviceTemplate] {
+        &self.templates
+    }
+
+    pub fn get(&self, id: &str) -> Option<&DeviceTemplate> {
+        self.templates.iter().find(|t| t.id == id)
+    }
+}
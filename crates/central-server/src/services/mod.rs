@@ -1,3 +1,5 @@
 pub use config_service::ConfigService;
+pub use device_templates::{DeviceTemplate, DeviceTemplateCatalog};
 
 pub mod config_service;
+pub mod device_templates;
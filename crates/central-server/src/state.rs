@@ -1,3 +1,4 @@
+use crate::services::DeviceTemplateCatalog;
 use infrastructure::MqttClient;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
@@ -36,6 +37,26 @@ pub struct TagData {
     pub received_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Compact tag update sent to SSE clients subscribed in delta mode.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TagDelta {
+    pub id: String,
+    pub value: serde_json::Value,
+    pub quality: String,
+    pub ts: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&TagData> for TagDelta {
+    fn from(tag: &TagData) -> Self {
+        Self {
+            id: tag.id.clone(),
+            value: tag.value.clone(),
+            quality: tag.quality.clone(),
+            ts: tag.timestamp,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ReportData {
     pub report_id: String,
@@ -51,6 +72,9 @@ pub enum SystemEvent {
     TagChanged(TagData),
     AgentStatusChanged(AgentData),
     ReportCompleted(ReportData),
+    // Delta mode only: never broadcast, built per SSE subscriber
+    TagDelta(TagDelta),
+    TagSnapshot(Vec<TagData>),
 }
 
 pub struct AppState {
@@ -60,6 +84,7 @@ pub struct AppState {
     pub pool: sqlx::PgPool,
     pub buffer: infrastructure::database::SQLiteBuffer,
     pub tx: broadcast::Sender<SystemEvent>,
+    pub device_templates: DeviceTemplateCatalog,
 }
 
 impl AppState {
@@ -76,6 +101,7 @@ impl AppState {
             pool,
             buffer,
             tx,
+            device_templates: DeviceTemplateCatalog::builtin(),
         }
     }
 
@@ -241,6 +267,11 @@ impl AppState {
         let _ = self.tx.send(SystemEvent::TagChanged(tag_data));
     }
 
+    pub fn tag_snapshot(&self) -> Vec<TagData> {
+        let tags = self.tags.read().unwrap();
+        tags.values().cloned().collect()
+    }
+
     pub async fn load_agents_from_db(&self) -> Result<(), sqlx::Error> {
         // V2: edge_agents has no heartbeat_interval_secs / missed_heartbeat_threshold columns
         let rows = sqlx::query("SELECT id, status FROM edge_agents")
@@ -0,0 +1,55 @@
+use central_server::services::{DeviceTemplate, DeviceTemplateCatalog};
+use serde_json::json;
+use std::collections::HashSet;
+
+#[test]
+fn test_builtin_templates_load() {
+    let catalog = DeviceTemplateCatalog::builtin();
+    assert!(!catalog.list().is_empty());
+
+    let mut ids = HashSet::new();
+    for template in catalog.list() {
+        assert!(
+            ids.insert(&template.id),
+            "Duplicate template id {}",
+            template.id
+        );
+        assert!(!template.tags.is_empty(), "{} has no tags", template.id);
+
+        let keys: HashSet<_> = template.tags.iter().map(|t| &t.key).collect();
+        assert_eq!(
+            keys.len(),
+            template.tags.len(),
+            "{} repeats a tag key",
+            template.id
+        );
+    }
+
+    assert!(catalog.get("mettler-toledo-scale").is_some());
+    assert!(catalog.get("does-not-exist").is_none());
+}
+
+#[test]
+fn test_connection_config_overrides() {
+    let catalog = DeviceTemplateCatalog::builtin();
+    let template = catalog.get("modbus-thermohygrometer").unwrap();
+
+    let config = template.connection_config_with(Some(&json!({ "port": "COM10", "slave_id": 84 })));
+
+    assert_eq!(config["port"], "COM10");
+    assert_eq!(config["slave_id"], 84);
+    // Untouched defaults are kept
+    assert_eq!(config["baud_rate"], 9600);
+    assert_eq!(
+        template.connection_config_with(None),
+        template.connection_config
+    );
+}
+
+#[test]
+fn test_tag_id_is_scoped_to_device() {
+    assert_eq!(
+        DeviceTemplate::tag_id("scale_02", "weight"),
+        "scale_02_weight"
+    );
+}
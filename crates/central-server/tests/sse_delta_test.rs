@@ -0,0 +1,37 @@
+use central_server::state::{SystemEvent, TagData, TagDelta};
+use serde_json::json;
+
+fn sample_tag() -> TagData {
+    TagData {
+        id: "TAG-1".to_string(),
+        agent_id: "agent-1".to_string(),
+        value: json!(12.5),
+        quality: "Good".to_string(),
+        status: "online".to_string(),
+        timestamp: chrono::Utc::now(),
+        received_at: Some(chrono::Utc::now()),
+    }
+}
+
+#[test]
+fn test_tag_delta_carries_only_compact_fields() {
+    let tag = sample_tag();
+    let event = serde_json::to_value(SystemEvent::TagDelta(TagDelta::from(&tag))).unwrap();
+
+    assert_eq!(event["type"], "TagDelta");
+    let payload = event["payload"].as_object().unwrap();
+    let mut keys: Vec<_> = payload.keys().map(String::as_str).collect();
+    keys.sort();
+    assert_eq!(keys, vec!["id", "quality", "ts", "value"]);
+    assert_eq!(payload["id"], "TAG-1");
+    assert_eq!(payload["value"], json!(12.5));
+}
+
+#[test]
+fn test_tag_snapshot_serializes_full_tag_list() {
+    let event = serde_json::to_value(SystemEvent::TagSnapshot(vec![sample_tag()])).unwrap();
+
+    assert_eq!(event["type"], "TagSnapshot");
+    assert_eq!(event["payload"][0]["agent_id"], "agent-1");
+    assert_eq!(event["payload"][0]["status"], "online");
+}
@@ -3,12 +3,68 @@ use application::device::DeviceManager;
 use domain::tag::{Tag, TagId, TagRepository, TagUpdateMode, TagValueType};
 use infrastructure::config::{AgentConfig, TagConfig};
 use infrastructure::{MqttClient, MqttMessage};
+use serde::Serialize;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tracing::info;
 
-use domain::device::DeviceRepository;
+use domain::device::{Device, DeviceRepository};
+
+/// Outcome of a hot reload, published to `scada/config/{agent_id}/ack`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigAck {
+    pub version: Option<String>,
+    pub status: ConfigAckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed_devices: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConfigAckStatus {
+    Applied,
+    /// New config was tried but a changed device failed to start; previous state restored
+    RolledBack,
+    /// Config was never applied (unparseable, or current state could not be snapshotted)
+    Rejected,
+}
+
+impl ConfigAck {
+    fn rejected(version: Option<String>, error: String) -> Self {
+        Self {
+            version,
+            status: ConfigAckStatus::Rejected,
+            error: Some(error),
+            failed_devices: vec![],
+        }
+    }
+}
+
+/// Devices and tags that were running before a reload.
+struct ReloadSnapshot {
+    devices: Vec<Device>,
+    tags: Vec<Tag>,
+}
+
+/// IDs of devices in `next` that are new or whose definition differs from `previous`.
+/// Only these may veto a reload: a device that was already failing is not the new config's fault.
+pub fn changed_device_ids(previous: &[Device], next: &[Device]) -> HashSet<String> {
+    next.iter()
+        .filter(|d| match previous.iter().find(|p| p.id == d.id) {
+            Some(p) => {
+                p.driver != d.driver
+                    || p.connection_config != d.connection_config
+                    || p.enabled != d.enabled
+            }
+            None => true,
+        })
+        .map(|d| d.id.clone())
+        .collect()
+}
 
 pub struct ConfigManager {
     mqtt_client: MqttClient,
@@ -133,16 +189,26 @@ impl ConfigManager {
                     }
                 }
 
-                // 2. Save to file
+                // 2. Save to file (keep the previous file to restore it if the reload fails)
+                let previous_file = tokio::fs::read(&self.config_path).await.ok();
                 match tokio::fs::write(&self.config_path, &save_payload).await {
                     Ok(_) => info!("✅ Configuration saved to {:?}", self.config_path),
                     Err(e) => tracing::error!("Failed to write config file: {}", e),
                 }
 
                 // 3. Hot Reload (Keep 'mqtt' in payload as AgentConfig requires it for deserialization)
-                self.handle_reload(&clean_payload).await;
+                let ack = self.handle_reload(&clean_payload).await;
 
-                // 4. Ack the message
+                if ack.status != ConfigAckStatus::Applied {
+                    // Forget the payload so the same config can be retried once the fault is fixed
+                    self.last_config_payload.lock().await.clear();
+                    self.restore_config_file(previous_file).await;
+                }
+
+                // 4. Report the outcome to the central server
+                self.publish_ack(&ack).await;
+
+                // 5. Ack the message
                 if let Err(e) = self.mqtt_client.ack(&msg.topic, msg.pkid).await {
                     tracing::error!("Failed to ack config update: {}", e);
                 }
@@ -150,7 +216,33 @@ impl ConfigManager {
         }
     }
 
-    async fn handle_reload(&self, payload: &[u8]) {
+    async fn restore_config_file(&self, previous: Option<Vec<u8>>) {
+        let res = match previous {
+            Some(bytes) => tokio::fs::write(&self.config_path, bytes).await,
+            None => tokio::fs::remove_file(&self.config_path).await,
+        };
+        match res {
+            Ok(_) => info!(
+                "⏪ Restored previous configuration file {:?}",
+                self.config_path
+            ),
+            Err(e) => tracing::error!("Failed to restore config file: {}", e),
+        }
+    }
+
+    async fn publish_ack(&self, ack: &ConfigAck) {
+        let topic = format!("scada/config/{}/ack", self.agent_id);
+        match serde_json::to_string(ack) {
+            Ok(payload) => {
+                if let Err(e) = self.mqtt_client.publish(&topic, &payload, false).await {
+                    tracing::error!("Failed to publish config ack: {}", e);
+                }
+            }
+            Err(e) => tracing::error!("Failed to serialize config ack: {}", e),
+        }
+    }
+
+    async fn handle_reload(&self, payload: &[u8]) -> ConfigAck {
         info!("🔄 Initiating Hot Reload...");
 
         // Parse Config
@@ -158,23 +250,125 @@ impl ConfigManager {
             Ok(c) => c,
             Err(e) => {
                 tracing::error!("Failed to parse configuration for reload: {}", e);
-                return;
+                return ConfigAck::rejected(None, format!("Invalid configuration: {}", e));
             }
         };
+        let version = Some(config.version.clone());
 
-        // Update Shared Version
+        // Snapshot the running state so a failed start can be undone
+        let snapshot = match self.snapshot().await {
+            Ok(s) => s,
+            Err(e) => {
+                tracing::error!("Failed to snapshot current state, refusing reload: {}", e);
+                return ConfigAck::rejected(version, format!("Snapshot failed: {}", e));
+            }
+        };
+
+        // Persist Devices & Tags to DB
+        let new_tags: Vec<Tag> = config
+            .tags
+            .iter()
+            .map(|cfg| self.convert_config_to_tag(cfg))
+            .collect();
+        self.write_state(&config.devices, &new_tags).await;
+
+        // Load Domain Tags & Devices from Repo (ensure we have latest state)
+        let loaded = match (
+            self.tag_repository.find_by_agent(&self.agent_id).await,
+            self.device_repository.find_by_agent(&self.agent_id).await,
+        ) {
+            (Ok(tags), Ok(devices)) => Ok((devices, tags)),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        };
+        let (devices, tags) = match loaded {
+            Ok(state) => state,
+            Err(e) => {
+                tracing::error!("Failed to load new config from storage: {}", e);
+                self.rollback(&snapshot).await;
+                return ConfigAck {
+                    version,
+                    status: ConfigAckStatus::RolledBack,
+                    error: Some(format!("Failed to load new config: {}", e)),
+                    failed_devices: vec![],
+                };
+            }
+        };
+
+        info!("Stopping {} active devices...", "all"); // DeviceManager doesn't expose count yet easily
+        self.device_manager.stop_all().await;
+
+        info!("Starting {} devices...", devices.len());
+        let changed = changed_device_ids(&snapshot.devices, &config.devices);
+        let failures: Vec<_> = self
+            .device_manager
+            .start_devices(devices, tags)
+            .await
+            .into_iter()
+            .filter(|f| changed.contains(&f.device_id))
+            .collect();
+
+        if !failures.is_empty() {
+            let error = failures
+                .iter()
+                .map(|f| format!("{}: {}", f.device_id, f.error))
+                .collect::<Vec<_>>()
+                .join("; ");
+            tracing::error!("❌ Hot Reload failed to start devices ({})", error);
+            self.rollback(&snapshot).await;
+            return ConfigAck {
+                version,
+                status: ConfigAckStatus::RolledBack,
+                error: Some(error),
+                failed_devices: failures.into_iter().map(|f| f.device_id).collect(),
+            };
+        }
+
+        // Commit: only now expose the new version and automations
         {
             let mut v = self.config_version.write().unwrap();
             *v = config.version.clone();
             info!("🔄 Config Version updated to: {}", *v);
         }
-
-        // Reload Automations
         self.automation_engine.reload(config.tags.clone()).await;
 
-        // Persist Devices to DB
-        let mut new_device_ids = std::collections::HashSet::new();
-        for device in &config.devices {
+        info!("✅ Hot Reload Complete");
+        ConfigAck {
+            version,
+            status: ConfigAckStatus::Applied,
+            error: None,
+            failed_devices: vec![],
+        }
+    }
+
+    async fn snapshot(&self) -> Result<ReloadSnapshot, domain::DomainError> {
+        Ok(ReloadSnapshot {
+            devices: self.device_repository.find_by_agent(&self.agent_id).await?,
+            tags: self.tag_repository.find_by_agent(&self.agent_id).await?,
+        })
+    }
+
+    async fn rollback(&self, snapshot: &ReloadSnapshot) {
+        tracing::warn!(
+            "⏪ Rolling back to previous configuration ({} devices, {} tags)",
+            snapshot.devices.len(),
+            snapshot.tags.len()
+        );
+        self.write_state(&snapshot.devices, &snapshot.tags).await;
+
+        self.device_manager.stop_all().await;
+        let failures = self
+            .device_manager
+            .start_devices(snapshot.devices.clone(), snapshot.tags.clone())
+            .await;
+        if !failures.is_empty() {
+            tracing::warn!("{} device(s) still failing after rollback", failures.len());
+        }
+    }
+
+    /// Upserts the given devices and tags and deletes any others stored for this agent.
+    async fn write_state(&self, devices: &[Device], tags: &[Tag]) {
+        let mut new_device_ids = HashSet::new();
+        for device in devices {
             new_device_ids.insert(device.id.clone());
             if let Err(e) = self.device_repository.save(device).await {
                 tracing::error!("Failed to save device {}: {}", device.id, e);
@@ -193,20 +387,15 @@ impl ConfigManager {
             }
         }
 
-        // Persist Tags to DB
-        // TODO: Handle deletions (currently only upserts)
-        // Strategy for deletion: get all tags for agent, find diff, delete missing.
-        let mut new_tag_ids = std::collections::HashSet::new();
-
-        for tag_cfg in &config.tags {
-            let tag = self.convert_config_to_tag(tag_cfg);
+        let mut new_tag_ids = HashSet::new();
+        for tag in tags {
             new_tag_ids.insert(tag.id().clone());
-            if let Err(e) = self.tag_repository.save(&tag).await {
+            if let Err(e) = self.tag_repository.save(tag).await {
                 tracing::error!("Failed to save tag {}: {}", tag.id(), e);
             }
         }
 
-        // Handle deletions
+        // Handle tag deletions
         if let Ok(existing_tags) = self.tag_repository.find_by_agent(&self.agent_id).await {
             for existing in existing_tags {
                 if !new_tag_ids.contains(existing.id()) {
@@ -217,34 +406,6 @@ impl ConfigManager {
                 }
             }
         }
-
-        // Load Domain Tags from Repo
-        let tags = match self.tag_repository.find_by_agent(&self.agent_id).await {
-            Ok(t) => t,
-            Err(e) => {
-                tracing::error!("Failed to load tags from new config: {}", e);
-                return;
-            }
-        };
-
-        // Load Domain Devices from Repo (ensure we have latest state)
-        let devices = match self.device_repository.find_by_agent(&self.agent_id).await {
-            Ok(d) => d,
-            Err(e) => {
-                tracing::error!("Failed to load devices from new config: {}", e);
-                return;
-            }
-        };
-
-        info!("Stopping {} active devices...", "all"); // DeviceManager doesn't expose count yet easily
-        self.device_manager.stop_all().await;
-
-        if !devices.is_empty() {
-            info!("Starting {} devices...", devices.len());
-            self.device_manager.start_devices(devices, tags).await;
-        }
-
-        info!("✅ Hot Reload Complete");
     }
 
     fn convert_config_to_tag(&self, cfg: &TagConfig) -> Tag {
@@ -0,0 +1,44 @@
+use domain::device::Device;
+use domain::driver::DriverType;
+use edge_agent::config_manager::changed_device_ids;
+use serde_json::json;
+
+fn rs232(id: &str, port: &str) -> Device {
+    Device::new(
+        id.to_string(),
+        DriverType::RS232,
+        json!({ "port": port, "baud_rate": 9600 }),
+        true,
+    )
+}
+
+#[test]
+fn test_unchanged_devices_cannot_veto_reload() {
+    let previous = vec![rs232("scale_01", "COM7"), rs232("scale_02", "COM8")];
+    let next = previous.clone();
+
+    assert!(changed_device_ids(&previous, &next).is_empty());
+}
+
+#[test]
+fn test_new_and_modified_devices_are_changed() {
+    let previous = vec![rs232("scale_01", "COM7"), rs232("scale_02", "COM8")];
+    let mut disabled = rs232("scale_02", "COM8");
+    disabled.enabled = false;
+    let next = vec![rs232("scale_01", "COM9"), disabled, rs232("scale_03", "COM3")];
+
+    let changed = changed_device_ids(&previous, &next);
+
+    assert_eq!(changed.len(), 3);
+    assert!(changed.contains("scale_01"));
+    assert!(changed.contains("scale_02"));
+    assert!(changed.contains("scale_03"));
+}
+
+#[test]
+fn test_removed_devices_are_not_reported() {
+    let previous = vec![rs232("scale_01", "COM7"), rs232("scale_02", "COM8")];
+    let next = vec![rs232("scale_01", "COM7")];
+
+    assert!(changed_device_ids(&previous, &next).is_empty());
+}
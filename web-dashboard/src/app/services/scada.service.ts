@@ -51,6 +51,33 @@ export interface TagHistoryEntry {
     created_at: string;
 }
 
+export interface DeviceTemplate {
+    id: string;
+    name: string;
+    manufacturer: string;
+    category: string;
+    description: string;
+    driver: string;
+    connection_config: Record<string, any>;
+    tags: Array<{
+        key: string;
+        description: string;
+        source_config: any;
+        update_mode: string;
+        update_config: any;
+        value_type: 'Simple' | 'Composite';
+        value_schema?: any;
+        pipeline?: any;
+    }>;
+}
+
+export interface ApplyDeviceTemplateRequest {
+    agent_id: string;
+    device_id: string;
+    name?: string;
+    connection_config?: Record<string, any>;
+}
+
 @Injectable({
     providedIn: 'root'
 })
@@ -90,4 +117,12 @@ export class ScadaService {
     batchPrintEvents(eventIds: number[]): Observable<any> {
         return this.http.post(`${this.baseUrl}/tags/batch-print`, { event_ids: eventIds });
     }
+
+    getDeviceTemplates(): Observable<DeviceTemplate[]> {
+        return this.http.get<DeviceTemplate[]>(`${this.baseUrl}/device-templates`);
+    }
+
+    applyDeviceTemplate(id: string, req: ApplyDeviceTemplateRequest): Observable<any> {
+        return this.http.post(`${this.baseUrl}/device-templates/${id}/apply`, req);
+    }
 }
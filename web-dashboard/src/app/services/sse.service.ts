@@ -43,12 +43,32 @@ export interface ReportCompletedEvent {
 
 export type ScadaEvent = TagChangedEvent | AgentStatusEvent | ReportCompletedEvent;
 
+// Wire-only events sent by /api/events?mode=delta
+interface TagDeltaEvent {
+    type: 'TagDelta';
+    payload: {
+        id: string;
+        value: any;
+        quality: string;
+        ts: string;
+    };
+}
+
+interface TagSnapshotEvent {
+    type: 'TagSnapshot';
+    payload: TagChangedEvent['payload'][];
+}
+
+type WireEvent = ScadaEvent | TagDeltaEvent | TagSnapshotEvent;
+
 @Injectable({
     providedIn: 'root'
 })
 export class SseService {
-    private url = `http://${window.location.hostname}:3000/api/events`;
+    private url = `http://${window.location.hostname}:3000/api/events?mode=delta`;
     private eventSubject = new Subject<ScadaEvent>();
+    // Last known full tag state, used to expand deltas back into TagChanged
+    private tagCache = new Map<string, TagChangedEvent['payload']>();
 
     constructor(private zone: NgZone) {
         this.connect();
@@ -60,8 +80,8 @@ export class SseService {
         eventSource.onmessage = (event) => {
             this.zone.run(() => {
                 try {
-                    const data: ScadaEvent = JSON.parse(event.data);
-                    this.eventSubject.next(data);
+                    const data: WireEvent = JSON.parse(event.data);
+                    this.dispatch(data);
                 } catch (e) {
                     console.error('Error parsing SSE event', e);
                 }
@@ -74,6 +94,30 @@ export class SseService {
         };
     }
 
+    private dispatch(event: WireEvent) {
+        if (event.type === 'TagDelta') {
+            const { id, value, quality, ts } = event.payload;
+            const existing = this.tagCache.get(id);
+            // agent_id is left out when unknown so consumers keep their own copy
+            const payload = {
+                ...existing, id, value, quality, timestamp: ts, received_at: undefined
+            } as TagChangedEvent['payload'];
+            this.tagCache.set(id, payload);
+            this.eventSubject.next({ type: 'TagChanged', payload });
+        } else if (event.type === 'TagSnapshot') {
+            // Only re-emit tags whose state moved on since we last saw them (missed deltas)
+            for (const tag of event.payload) {
+                const existing = this.tagCache.get(tag.id);
+                this.tagCache.set(tag.id, tag);
+                if (existing && existing.timestamp !== tag.timestamp) {
+                    this.eventSubject.next({ type: 'TagChanged', payload: tag });
+                }
+            }
+        } else {
+            this.eventSubject.next(event);
+        }
+    }
+
     getEvents(): Observable<ScadaEvent> {
         return this.eventSubject.asObservable();
     }